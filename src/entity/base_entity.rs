@@ -1,7 +1,7 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne, FromQueryResult, Insert,
+    ActiveModelTrait, ColumnTrait, DbErr, Delete, DeleteMany, DeleteOne, FromQueryResult, Insert,
     ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related, RelationBuilder,
-    RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    RelationTrait, RelationType, Select, SelectTwo, SelectTwoMany, Update, UpdateMany, UpdateOne,
 };
 use sea_query::{Iden, IntoValueTuple};
 use std::fmt::Debug;
@@ -196,23 +196,146 @@ pub trait EntityTrait: EntityName {
     ///     )]);
     /// ```
     fn find_by_id<V>(values: V) -> Select<Self>
+    where
+        V: IntoValueTuple,
+    {
+        Self::try_find_by_id(values).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Find a model by primary key, returning a [`DbErr`] instead of panicking
+    /// when the number of supplied values does not match the arity of
+    /// `Self::PrimaryKey`.
+    ///
+    /// This is useful when the key values come from untrusted input (e.g. a
+    /// tuple parsed from route params) and an arity mismatch should be
+    /// reported to the caller rather than crash the process.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake_filling};
+    ///
+    /// assert!(cake_filling::Entity::try_find_by_id(2).is_err());
+    /// assert!(cake_filling::Entity::try_find_by_id((2, 3)).is_ok());
+    /// ```
+    fn try_find_by_id<V>(values: V) -> Result<Select<Self>, DbErr>
     where
         V: IntoValueTuple,
     {
         let mut select = Self::find();
         let mut keys = Self::PrimaryKey::iter();
-        for v in values.into_value_tuple() {
-            if let Some(key) = keys.next() {
-                let col = key.into_column();
-                select = select.filter(col.eq(v));
-            } else {
-                panic!("primary key arity mismatch");
-            }
+        let values: Vec<_> = values.into_value_tuple().into_iter().collect();
+        let expected = Self::PrimaryKey::iter().count();
+        if expected != values.len() {
+            return Err(DbErr::Custom(format!(
+                "primary key arity mismatch: expected {} value(s) for column(s) {}, got {}",
+                expected,
+                Self::PrimaryKey::iter()
+                    .map(|key| key.into_column().as_str().to_owned())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                values.len(),
+            )));
         }
-        if keys.next().is_some() {
-            panic!("primary key arity mismatch");
+        for v in values {
+            let key = keys.next().expect("arity already validated above");
+            let col = key.into_column();
+            select = select.filter(col.eq(v));
         }
-        select
+        Ok(select)
+    }
+
+    /// Find a model together with its related model of entity `R`, loaded via a
+    /// LEFT JOIN on the `Related` edge between `Self` and `R`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # use sea_orm::{error::*, MockDatabase, tests_cfg::*};
+    /// #
+    /// # let db = MockDatabase::new()
+    /// #     .append_query_results(vec![vec![
+    /// #         (
+    /// #             cake::Model { id: 1, name: "New York Cheese".to_owned() },
+    /// #             Some(fruit::Model { id: 1, name: "Apple".to_owned(), cake_id: Some(1) }),
+    /// #         ),
+    /// #         (
+    /// #             cake::Model { id: 2, name: "Chocolate Forest".to_owned() },
+    /// #             None,
+    /// #         ),
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// # let _: Result<(), DbErr> = async_std::task::block_on(async {
+    /// #
+    /// assert_eq!(
+    ///     cake::Entity::find_also_related(fruit::Entity).all(&db).await?,
+    ///     vec![
+    ///         (
+    ///             cake::Model { id: 1, name: "New York Cheese".to_owned() },
+    ///             Some(fruit::Model { id: 1, name: "Apple".to_owned(), cake_id: Some(1) }),
+    ///         ),
+    ///         (
+    ///             cake::Model { id: 2, name: "Chocolate Forest".to_owned() },
+    ///             None,
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # });
+    /// ```
+    fn find_also_related<R>(_: R) -> SelectTwo<Self, R>
+    where
+        R: EntityTrait,
+        Self: Related<R>,
+    {
+        SelectTwo::new(Self::find())
+    }
+
+    /// Find a model together with all of its related models of entity `R`,
+    /// loaded via a LEFT JOIN on the `Related` edge between `Self` and `R` and
+    /// grouped by `Self`'s primary key so each parent appears once.
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # use sea_orm::{error::*, MockDatabase, tests_cfg::*};
+    /// #
+    /// # let db = MockDatabase::new()
+    /// #     .append_query_results(vec![vec![
+    /// #         (
+    /// #             cake::Model { id: 1, name: "New York Cheese".to_owned() },
+    /// #             Some(fruit::Model { id: 1, name: "Apple".to_owned(), cake_id: Some(1) }),
+    /// #         ),
+    /// #         (
+    /// #             cake::Model { id: 1, name: "New York Cheese".to_owned() },
+    /// #             Some(fruit::Model { id: 2, name: "Orange".to_owned(), cake_id: Some(1) }),
+    /// #         ),
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// # let _: Result<(), DbErr> = async_std::task::block_on(async {
+    /// #
+    /// assert_eq!(
+    ///     cake::Entity::find_with_related(fruit::Entity).all(&db).await?,
+    ///     vec![(
+    ///         cake::Model { id: 1, name: "New York Cheese".to_owned() },
+    ///         vec![
+    ///             fruit::Model { id: 1, name: "Apple".to_owned(), cake_id: Some(1) },
+    ///             fruit::Model { id: 2, name: "Orange".to_owned(), cake_id: Some(1) },
+    ///         ],
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # });
+    /// ```
+    fn find_with_related<R>(_: R) -> SelectTwoMany<Self, R>
+    where
+        R: EntityTrait,
+        Self: Related<R>,
+    {
+        SelectTwoMany::new(Self::find())
     }
 
     /// ```
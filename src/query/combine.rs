@@ -0,0 +1,225 @@
+use crate::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Iterable, JoinType,
+    ModelTrait, PrimaryKeyToColumn, QueryResult, QuerySelect, QueryTrait, Related, Select,
+};
+use core::marker::PhantomData;
+use sea_query::{Expr, SelectStatement, Value};
+use std::collections::HashMap;
+
+/// Alias prefix under which columns of the related entity are selected, so that
+/// `Model` and `R::Model` can be deserialized from the same row without name
+/// collisions even when both entities share a column name (e.g. `id`).
+pub(crate) const RELATED_COLUMN_ALIAS: &str = "A_B_";
+
+/// Root entity paired with an optional related entity, produced by a LEFT JOIN.
+///
+/// Constructed via [`EntityTrait::find_also_related`].
+#[derive(Clone, Debug)]
+pub struct SelectTwo<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    pub(crate) query: SelectStatement,
+    entity: PhantomData<E>,
+    entity_two: PhantomData<F>,
+}
+
+/// Root entity paired with a `Vec` of related entities, produced by a LEFT JOIN
+/// whose rows are grouped by the root entity's primary key so each parent
+/// appears once alongside all of its children.
+///
+/// Constructed via [`EntityTrait::find_with_related`].
+#[derive(Clone, Debug)]
+pub struct SelectTwoMany<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    pub(crate) query: SelectStatement,
+    entity: PhantomData<E>,
+    entity_two: PhantomData<F>,
+}
+
+/// Builds the shared LEFT JOIN query: join `F`'s table in via the `Related` edge
+/// from `E`, then select all of `F`'s columns under the `A_B_`-prefixed alias so
+/// they can be split back out of the row alongside `E`'s own columns.
+fn join_and_select<E, F>(select: Select<E>) -> SelectStatement
+where
+    E: EntityTrait + Related<F>,
+    F: EntityTrait,
+{
+    let mut select = select.join(JoinType::LeftJoin, E::to());
+    for col in F::Column::iter() {
+        select = select.column_as(
+            Expr::tbl(F::default(), col),
+            format!("{}{}", RELATED_COLUMN_ALIAS, col.as_str()),
+        );
+    }
+    select.into_query()
+}
+
+/// Whether the LEFT JOIN found a matching `F` row, decided by checking `F`'s
+/// primary key column(s) (under their `A_B_`-prefixed alias) for `NULL` rather
+/// than by swallowing whatever error `F::Model` deserialization happens to
+/// raise — a real deserialization failure (bad column type, driver quirk, a
+/// genuinely malformed row) must still propagate as a `DbErr`.
+fn related_is_present<F>(row: &QueryResult) -> Result<bool, DbErr>
+where
+    F: EntityTrait,
+{
+    for pk in F::PrimaryKey::iter() {
+        let col = pk.into_column();
+        let value: Value = row.try_get(RELATED_COLUMN_ALIAS, col.as_str())?;
+        if !value.is_null() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Splits a joined row back into `(E::Model, Option<F::Model>)`. The related
+/// side is `None` only when [`related_is_present`] reports no match; any other
+/// deserialization error is propagated.
+fn split_row<E, F>(row: &QueryResult) -> Result<(E::Model, Option<F::Model>), DbErr>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    let model = E::Model::from_query_result(row, "")?;
+    let related = if related_is_present::<F>(row)? {
+        Some(F::Model::from_query_result(row, RELATED_COLUMN_ALIAS)?)
+    } else {
+        None
+    };
+    Ok((model, related))
+}
+
+/// Extracts `E`'s primary key value(s) from a model, used as the grouping key
+/// when collapsing joined rows back down to one entry per parent.
+fn primary_key_of<E>(model: &E::Model) -> Vec<Value>
+where
+    E: EntityTrait,
+{
+    E::PrimaryKey::iter()
+        .map(|pk| model.get(pk.into_column()))
+        .collect()
+}
+
+impl<E, F> SelectTwo<E, F>
+where
+    E: EntityTrait + Related<F>,
+    F: EntityTrait,
+{
+    pub(crate) fn new(select: Select<E>) -> Self {
+        Self {
+            query: join_and_select::<E, F>(select),
+            entity: PhantomData,
+            entity_two: PhantomData,
+        }
+    }
+
+    /// Get one model alongside its related model from the database
+    pub async fn one<C>(self, db: &C) -> Result<Option<(E::Model, Option<F::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let row = db.query_one(self.build(db.get_database_backend())).await?;
+        row.as_ref().map(split_row::<E, F>).transpose()
+    }
+
+    /// Get all models alongside their related models from the database
+    pub async fn all<C>(self, db: &C) -> Result<Vec<(E::Model, Option<F::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let rows = db.query_all(self.build(db.get_database_backend())).await?;
+        rows.iter().map(split_row::<E, F>).collect()
+    }
+}
+
+impl<E, F> SelectTwoMany<E, F>
+where
+    E: EntityTrait + Related<F>,
+    F: EntityTrait,
+{
+    pub(crate) fn new(select: Select<E>) -> Self {
+        Self {
+            query: join_and_select::<E, F>(select),
+            entity: PhantomData,
+            entity_two: PhantomData,
+        }
+    }
+
+    /// Get one model alongside all of its related models from the database
+    pub async fn one<C>(self, db: &C) -> Result<Option<(E::Model, Vec<F::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(self.all(db).await?.into_iter().next())
+    }
+
+    /// Get all models alongside their related models from the database, grouping
+    /// rows by `E`'s primary key (via a `HashMap` index, not a linear scan) so
+    /// that each parent appears exactly once.
+    pub async fn all<C>(self, db: &C) -> Result<Vec<(E::Model, Vec<F::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let rows = db.query_all(self.build(db.get_database_backend())).await?;
+        let mut grouped: Vec<(E::Model, Vec<F::Model>)> = Vec::new();
+        let mut index: HashMap<Vec<Value>, usize> = HashMap::new();
+        for row in rows.iter() {
+            let (parent, child) = split_row::<E, F>(row)?;
+            let key = primary_key_of::<E>(&parent);
+            match index.get(&key) {
+                Some(&i) => grouped[i].1.extend(child),
+                None => {
+                    index.insert(key, grouped.len());
+                    grouped.push((parent, child.into_iter().collect()));
+                }
+            }
+        }
+        Ok(grouped)
+    }
+}
+
+impl<E, F> QueryTrait for SelectTwo<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    type QueryStatement = SelectStatement;
+
+    fn query(&mut self) -> &mut SelectStatement {
+        &mut self.query
+    }
+
+    fn as_query(&self) -> &SelectStatement {
+        &self.query
+    }
+
+    fn into_query(self) -> SelectStatement {
+        self.query
+    }
+}
+
+impl<E, F> QueryTrait for SelectTwoMany<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    type QueryStatement = SelectStatement;
+
+    fn query(&mut self) -> &mut SelectStatement {
+        &mut self.query
+    }
+
+    fn as_query(&self) -> &SelectStatement {
+        &self.query
+    }
+
+    fn into_query(self) -> SelectStatement {
+        self.query
+    }
+}
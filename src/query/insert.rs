@@ -0,0 +1,131 @@
+use crate::{ActiveModelTrait, EntityTrait, Iterable, QueryTrait};
+use core::marker::PhantomData;
+use sea_query::{Expr, InsertStatement};
+pub use sea_query::OnConflict;
+
+/// Performs INSERT operations on an entity
+#[derive(Debug)]
+pub struct Insert<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) query: InsertStatement,
+    pub(crate) columns: Vec<bool>,
+    pub(crate) model: PhantomData<A>,
+}
+
+impl<A> Default for Insert<A>
+where
+    A: ActiveModelTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Insert<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            query: InsertStatement::new()
+                .into_table(A::Entity::default())
+                .to_owned(),
+            columns: Vec::new(),
+            model: PhantomData,
+        }
+    }
+
+    pub(crate) fn one<M>(model: M) -> Self
+    where
+        M: Into<A>,
+    {
+        Self::new().add(model)
+    }
+
+    pub(crate) fn many<M, I>(models: I) -> Self
+    where
+        M: Into<A>,
+        I: IntoIterator<Item = M>,
+    {
+        let mut insert = Self::new();
+        for model in models.into_iter() {
+            insert = insert.add(model);
+        }
+        insert
+    }
+
+    fn add<M>(mut self, model: M) -> Self
+    where
+        M: Into<A>,
+    {
+        let model = model.into();
+        let mut columns = Vec::new();
+        let mut query_columns = Vec::new();
+        let mut values = Vec::new();
+        for col in <A::Entity as EntityTrait>::Column::iter() {
+            let av = model.get(col);
+            if av.is_set() || av.is_unchanged() {
+                columns.push(true);
+                query_columns.push(col);
+                values.push(av.into_value().unwrap_or(Expr::value(sea_query::Value::Int(None)).into()));
+            } else {
+                columns.push(false);
+            }
+        }
+        if self.columns.is_empty() {
+            self.columns = columns;
+        }
+        self.query.columns(query_columns);
+        self.query.values_panic(values);
+        self
+    }
+
+    /// Set the `ON CONFLICT` clause of the statement: the conflict target and
+    /// the action to take (update the given columns, or do nothing)
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::OnConflict, tests_cfg::cake, DbBackend};
+    ///
+    /// let orange = cake::ActiveModel {
+    ///     id: Set(1),
+    ///     name: Set("Orange".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::insert(orange)
+    ///         .on_conflict(
+    ///             OnConflict::column(cake::Column::Name)
+    ///                 .do_nothing()
+    ///                 .to_owned()
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Orange') ON CONFLICT ("name") DO NOTHING"#,
+    /// );
+    /// ```
+    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        self.query.on_conflict(on_conflict);
+        self
+    }
+}
+
+impl<A> QueryTrait for Insert<A>
+where
+    A: ActiveModelTrait,
+{
+    type QueryStatement = InsertStatement;
+
+    fn query(&mut self) -> &mut InsertStatement {
+        &mut self.query
+    }
+
+    fn as_query(&self) -> &InsertStatement {
+        &self.query
+    }
+
+    fn into_query(self) -> InsertStatement {
+        self.query
+    }
+}